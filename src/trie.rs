@@ -1,48 +1,77 @@
-use std::collections::HashMap;
-
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "K: serde::Serialize + Eq + Hash, V: serde::Serialize",
+        deserialize = "K: serde::Deserialize<'de> + Eq + Hash, V: serde::Deserialize<'de>"
+    ))
+)]
 #[derive(Debug)]
-pub struct Trie<T> {
-    children: HashMap<char, Trie<T>>,
+pub struct Trie<K, V> {
+    children: HashMap<K, Trie<K, V>>,
     is_leaf: bool,
-    value: Option<T>
+    value: Option<V>,
+    /// Trie built from the reversed keys, kept in sync by `insert`/`remove`
+    /// when present. Only ever set on the root; powers
+    /// [`Trie::search_prefix_suffix`] and absent otherwise.
+    reverse: Option<Box<Trie<K, V>>>
 }
 
-impl<T> Default for Trie<T> {
+impl<K, V> Default for Trie<K, V> {
     fn default() -> Self {
         Self {
             children: HashMap::new(),
             is_leaf: false,
-            value: None
+            value: None,
+            reverse: None
         }
     }
 }
 
-impl<T: Clone> Trie<T> {
+impl<K: Eq + Hash + Clone, V: Clone> Trie<K, V> {
     /// Creates a root node.
     pub fn new() -> Self { Trie::default() }
 
-    /// Returns `true` if the given word exists.
-    pub fn contains(&self, word: &str) -> bool {
+    /// Creates a root node that also maintains a reverse index, enabling
+    /// [`Trie::search_prefix_suffix`].
+    pub fn with_reverse_index() -> Self {
+        Self {
+            reverse: Some(Box::new(Trie::default())),
+            ..Trie::default()
+        }
+    }
+
+    /// Returns `true` if the given key sequence exists.
+    pub fn contains(&self, key: impl IntoIterator<Item = K>) -> bool {
         let mut current_node = self;
 
-        for c in word.chars() {
-            match current_node.children.get(&c) {
+        for k in key {
+            match current_node.children.get(&k) {
                 Some(next_node) => current_node = next_node,
                 None => return false
             }
         }
 
-        if current_node.value.is_some() { true } else { false }
+        current_node.value.is_some()
     }
 
-    /// Returns all the words from the Trie.
-    pub fn get_all(&self) -> Vec<(String, T)> { self.starts_with("") }
+    /// Returns all the entries from the Trie.
+    pub fn get_all(&self) -> Vec<(Vec<K>, V)> { self.starts_with(Vec::new()) }
 
-    /// Creates the nodes that represent a new word.
-    pub fn insert(&mut self, word: &str, value: Option<T>) {
-        let mut last_node = word.chars().fold(self, |current_node, c| {
+    /// Creates the nodes that represent a new key sequence.
+    pub fn insert(&mut self, key: impl IntoIterator<Item = K>, value: Option<V>) {
+        let key: Vec<K> = key.into_iter().collect();
+
+        if let Some(reverse) = &mut self.reverse {
+            reverse.insert(key.iter().cloned().rev(), value.clone());
+        }
+
+        let last_node = key.into_iter().fold(self, |current_node, k| {
             current_node.is_leaf = false;
-            current_node.children.entry(c).or_insert(Trie::default())
+            current_node.children.entry(k).or_insert(Trie::default())
         });
 
         last_node.value = value;
@@ -52,20 +81,26 @@ impl<T: Clone> Trie<T> {
         }
     }
 
-    /// Removes a word by reinitializing its `value`
+    /// Removes a key sequence by reinitializing its `value`
     /// and by updating leaf position as necessary.
-    pub fn remove(&mut self, word: &str) -> bool {
-        let previous_word_index = self.get_previous_word_index(word);
+    pub fn remove(&mut self, key: impl IntoIterator<Item = K>) -> bool {
+        let key: Vec<K> = key.into_iter().collect();
+
+        if let Some(reverse) = &mut self.reverse {
+            reverse.remove(key.iter().cloned().rev());
+        }
+
+        let previous_word_index = self.get_previous_word_index(key.clone());
         let mut current_node = self;
 
-        for (i, c) in word.chars().enumerate() {
+        for (i, k) in key.into_iter().enumerate() {
             if previous_word_index.is_some() &&
                previous_word_index.unwrap() == i
             {
                 current_node.is_leaf = true;
             }
 
-            match current_node.children.get_mut(&c) {
+            match current_node.children.get_mut(&k) {
                 Some(next_node) => current_node = next_node,
                 None => return false
             }
@@ -76,39 +111,40 @@ impl<T: Clone> Trie<T> {
         true
     }
 
-    /// Returns the words that start with the given prefix.
-    /// Words are returned in a tuple with their associated value.
-    pub fn starts_with(&self, prefix: &str) -> Vec<(String, T)> {
+    /// Returns the entries whose key starts with the given prefix.
+    /// Entries are returned in a tuple with their associated value.
+    pub fn starts_with(&self, prefix: impl IntoIterator<Item = K>) -> Vec<(Vec<K>, V)> {
+        let prefix: Vec<K> = prefix.into_iter().collect();
         let mut current_node = self;
 
-        for c in prefix.chars() {
-            match current_node.children.get(&c) {
+        for k in &prefix {
+            match current_node.children.get(k) {
                 Some(next_node) => current_node = next_node,
                 None => return Vec::new()
             }
         }
 
-        let mut words = current_node.dfs(prefix, "");
+        let mut words = current_node.dfs(&prefix, &[]);
 
-        // If it's a word, add the prefix itself.
+        // If it's an entry, add the prefix itself.
         if let Some(value) = &current_node.value {
-            words.push((prefix.to_string(), value.clone()));
+            words.push((prefix.clone(), value.clone()));
         }
 
         words
     }
 
-    /// Returns the index of the most direct parent for a given word.
-    fn get_previous_word_index(&self, word: &str) -> Option<usize> {
+    /// Returns the index of the most direct parent for a given key sequence.
+    fn get_previous_word_index(&self, key: impl IntoIterator<Item = K>) -> Option<usize> {
         let mut current_node = self;
         let mut previous_word_index = None;
 
-        for (i, c) in word.chars().enumerate() {
+        for (i, k) in key.into_iter().enumerate() {
             if current_node.value.is_some() {
                 previous_word_index = Some(i);
             }
 
-            match current_node.children.get(&c) {
+            match current_node.children.get(&k) {
                 Some(next_node) => current_node = next_node,
                 None => return None
             }
@@ -118,32 +154,514 @@ impl<T: Clone> Trie<T> {
     }
 
     /// Depth-first search.
-    fn dfs(&self, prefix: &str, buffer: &str) -> Vec<(String, T)> {
-        let depth = prefix.chars().count() + buffer.chars().count();
+    fn dfs(&self, prefix: &[K], buffer: &[K]) -> Vec<(Vec<K>, V)> {
+        let depth = prefix.len() + buffer.len();
         let mut words = Vec::new();
 
         for (k, v) in self.children.iter() {
-            let mut buffer = buffer.chars()
-                .into_iter()
+            let mut buffer = buffer.iter()
                 .take(depth)
-                .collect::<String>();
+                .cloned()
+                .collect::<Vec<K>>();
 
-            buffer.push(*k);
+            buffer.push(k.clone());
 
             if let Some(value) = &v.value {
-                let mut new_word = String::from(prefix);
-                new_word.push_str(&buffer);
+                let mut new_word = prefix.to_vec();
+                new_word.extend(buffer.iter().cloned());
 
                 words.push((new_word, value.clone()));
             }
 
-            if let Some(next_node) = self.children.get(&k) {
+            if let Some(next_node) = self.children.get(k) {
                 words.extend(next_node.dfs(prefix, &buffer));
             }
         }
 
         words
     }
+
+    /// Returns every stored entry whose key is within Levenshtein
+    /// distance `max_distance` of `query`.
+    pub fn search_fuzzy(&self, query: impl IntoIterator<Item = K>, max_distance: usize) -> Vec<(Vec<K>, V)> {
+        let query: Vec<K> = query.into_iter().collect();
+        let root_row: Vec<usize> = (0..=query.len()).collect();
+        let mut results = Vec::new();
+
+        self.fuzzy_dfs(&query, &root_row, max_distance, &mut Vec::new(), &mut results);
+
+        results
+    }
+
+    /// Returns every stored entry whose key is a prefix of `key`, in
+    /// order from shortest to longest. This is the dual of
+    /// [`Trie::starts_with`]: instead of finding stored entries that
+    /// extend a prefix, it finds stored entries that the query extends.
+    pub fn find_prefixes(&self, key: impl IntoIterator<Item = K>) -> Vec<(Vec<K>, &V)> {
+        let mut current_node = self;
+        let mut buffer = Vec::new();
+        let mut prefixes = Vec::new();
+
+        if let Some(value) = &current_node.value {
+            prefixes.push((buffer.clone(), value));
+        }
+
+        for k in key {
+            match current_node.children.get(&k) {
+                Some(next_node) => {
+                    current_node = next_node;
+                    buffer.push(k);
+
+                    if let Some(value) = &current_node.value {
+                        prefixes.push((buffer.clone(), value));
+                    }
+                }
+                None => break
+            }
+        }
+
+        prefixes
+    }
+
+    /// Returns the longest stored entry whose key is a prefix of `key`,
+    /// if any.
+    pub fn find_longest_prefix(&self, key: impl IntoIterator<Item = K>) -> Option<(Vec<K>, &V)> {
+        self.find_prefixes(key).pop()
+    }
+
+    /// Returns all stored entries whose key simultaneously starts with
+    /// `prefix` and ends with `suffix`.
+    pub fn search_prefix_suffix(
+        &self,
+        prefix: impl IntoIterator<Item = K>,
+        suffix: impl IntoIterator<Item = K>
+    ) -> Vec<(Vec<K>, V)> {
+        let reverse = match &self.reverse {
+            Some(reverse) => reverse,
+            None => return Vec::new()
+        };
+
+        let by_prefix: HashSet<Vec<K>> = self.starts_with(prefix)
+            .into_iter()
+            .map(|(word, _)| word)
+            .collect();
+
+        let mut reversed_suffix: Vec<K> = suffix.into_iter().collect();
+        reversed_suffix.reverse();
+
+        reverse.starts_with(reversed_suffix)
+            .into_iter()
+            .filter_map(|(reversed_word, value)| {
+                let word: Vec<K> = reversed_word.into_iter().rev().collect();
+
+                if by_prefix.contains(&word) {
+                    Some((word, value))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn fuzzy_dfs(
+        &self,
+        query: &[K],
+        row: &[usize],
+        max_distance: usize,
+        buffer: &mut Vec<K>,
+        results: &mut Vec<(Vec<K>, V)>
+    ) {
+        if let Some(value) = &self.value {
+            if *row.last().unwrap() <= max_distance {
+                results.push((buffer.clone(), value.clone()));
+            }
+        }
+
+        for (k, child) in self.children.iter() {
+            // Derive the child's edit-distance row from `row` (its parent's)
+            // instead of recomputing it from scratch for every candidate.
+            let mut new_row = Vec::with_capacity(row.len());
+            new_row.push(row[0] + 1);
+
+            for j in 1..row.len() {
+                let substitution_cost = if query[j - 1] == *k { 0 } else { 1 };
+                let deletion = row[j] + 1;
+                let insertion = new_row[j - 1] + 1;
+                let substitution = row[j - 1] + substitution_cost;
+
+                new_row.push(deletion.min(insertion).min(substitution));
+            }
+
+            // The distance can only grow deeper, so prune once it's already
+            // past max_distance everywhere in this row.
+            if *new_row.iter().min().unwrap() <= max_distance {
+                buffer.push(k.clone());
+                child.fuzzy_dfs(query, &new_row, max_distance, buffer, results);
+                buffer.pop();
+            }
+        }
+    }
+}
+
+/// Thin `&str` convenience layer over `Trie<char, V>` for callers that
+/// key on Unicode scalar values rather than an arbitrary symbol type.
+impl<V: Clone> Trie<char, V> {
+    /// Returns `true` if the given word exists.
+    pub fn contains_str(&self, word: &str) -> bool {
+        self.contains(word.chars())
+    }
+
+    /// Returns all the words from the Trie.
+    pub fn get_all_str(&self) -> Vec<(String, V)> {
+        self.starts_with_str("")
+    }
+
+    /// Creates the nodes that represent a new word.
+    pub fn insert_str(&mut self, word: &str, value: Option<V>) {
+        self.insert(word.chars(), value);
+    }
+
+    /// Removes a word by reinitializing its `value`
+    /// and by updating leaf position as necessary.
+    pub fn remove_str(&mut self, word: &str) -> bool {
+        self.remove(word.chars())
+    }
+
+    /// Returns the words that start with the given prefix.
+    /// Words are returned in a tuple with their associated value.
+    pub fn starts_with_str(&self, prefix: &str) -> Vec<(String, V)> {
+        self.starts_with(prefix.chars())
+            .into_iter()
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+            .collect()
+    }
+
+    /// Returns every stored word within Levenshtein distance
+    /// `max_distance` of `word`.
+    pub fn search_fuzzy_str(&self, word: &str, max_distance: usize) -> Vec<(String, V)> {
+        self.search_fuzzy(word.chars(), max_distance)
+            .into_iter()
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+            .collect()
+    }
+
+    /// Returns every stored word that is a prefix of `word`, shortest
+    /// to longest.
+    pub fn find_prefixes_str(&self, word: &str) -> Vec<(String, &V)> {
+        self.find_prefixes(word.chars())
+            .into_iter()
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+            .collect()
+    }
+
+    /// Returns the longest stored word that is a prefix of `word`, if any.
+    pub fn find_longest_prefix_str(&self, word: &str) -> Option<(String, &V)> {
+        self.find_prefixes_str(word).pop()
+    }
+
+    /// Returns every stored word that simultaneously starts with
+    /// `prefix` and ends with `suffix`.
+    pub fn search_prefix_suffix_str(&self, prefix: &str, suffix: &str) -> Vec<(String, V)> {
+        self.search_prefix_suffix(prefix.chars(), suffix.chars())
+            .into_iter()
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+            .collect()
+    }
+}
+
+/// Returns the length of the common prefix shared by `a` and `b`.
+fn common_prefix_len<K: PartialEq>(a: &[K], b: &[K]) -> usize {
+    a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count()
+}
+
+/// A path-compressed (radix) variant of [`Trie`]: an edge stores a whole
+/// key-sequence label instead of a single symbol.
+#[derive(Debug)]
+pub struct RadixTrie<K, V> {
+    children: HashMap<K, (Vec<K>, RadixTrie<K, V>)>,
+    value: Option<V>
+}
+
+impl<K, V> Default for RadixTrie<K, V> {
+    fn default() -> Self {
+        Self {
+            children: HashMap::new(),
+            value: None
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> RadixTrie<K, V> {
+    /// Creates a root node.
+    pub fn new() -> Self { RadixTrie::default() }
+
+    /// Returns `true` if the given key sequence exists.
+    pub fn contains(&self, key: impl IntoIterator<Item = K>) -> bool {
+        let key: Vec<K> = key.into_iter().collect();
+
+        match self.find_node(&key) {
+            Some(node) => node.value.is_some(),
+            None => false
+        }
+    }
+
+    /// Returns all the entries from the Trie.
+    pub fn get_all(&self) -> Vec<(Vec<K>, V)> { self.starts_with(Vec::new()) }
+
+    /// Creates the nodes (splitting an edge label if necessary) that
+    /// represent a new key sequence.
+    pub fn insert(&mut self, key: impl IntoIterator<Item = K>, value: Option<V>) {
+        let key: Vec<K> = key.into_iter().collect();
+        self.insert_rec(&key, value);
+    }
+
+    fn insert_rec(&mut self, key: &[K], value: Option<V>) {
+        if key.is_empty() {
+            self.value = value;
+            return;
+        }
+
+        let first = key[0].clone();
+
+        if let Some((label, _)) = self.children.get(&first) {
+            let common = common_prefix_len(label, key);
+
+            if common == label.len() {
+                let (_, child) = self.children.get_mut(&first).unwrap();
+                child.insert_rec(&key[common..], value);
+                return;
+            }
+
+            let (old_label, old_child) = self.children.remove(&first).unwrap();
+            let shared = old_label[..common].to_vec();
+            let old_rest = old_label[common..].to_vec();
+            let new_rest = key[common..].to_vec();
+
+            let mut intermediate = RadixTrie::default();
+            intermediate.children.insert(old_rest[0].clone(), (old_rest, old_child));
+
+            if new_rest.is_empty() {
+                intermediate.value = value;
+            } else {
+                let leaf = RadixTrie { children: HashMap::new(), value };
+                intermediate.children.insert(new_rest[0].clone(), (new_rest, leaf));
+            }
+
+            self.children.insert(first, (shared, intermediate));
+        } else {
+            let leaf = RadixTrie { children: HashMap::new(), value };
+            self.children.insert(first, (key.to_vec(), leaf));
+        }
+    }
+
+    /// Removes a key sequence, pruning dead edges and re-merging a node
+    /// with its sole remaining child so the tree stays compressed.
+    pub fn remove(&mut self, key: impl IntoIterator<Item = K>) -> bool {
+        let key: Vec<K> = key.into_iter().collect();
+        self.remove_rec(&key)
+    }
+
+    fn remove_rec(&mut self, key: &[K]) -> bool {
+        if key.is_empty() {
+            let removed = self.value.is_some();
+            self.value = None;
+            return removed;
+        }
+
+        let first = key[0].clone();
+
+        let removed = match self.children.get_mut(&first) {
+            Some((label, child)) => {
+                if key.len() < label.len() || &key[..label.len()] != label.as_slice() {
+                    return false;
+                }
+
+                let rest = &key[label.len()..];
+                child.remove_rec(rest)
+            }
+            None => return false
+        };
+
+        if removed {
+            let (prune, merge) = {
+                let (_, child) = self.children.get(&first).unwrap();
+                (
+                    child.value.is_none() && child.children.is_empty(),
+                    child.value.is_none() && child.children.len() == 1
+                )
+            };
+
+            if prune {
+                self.children.remove(&first);
+            } else if merge {
+                let (label, mut child) = self.children.remove(&first).unwrap();
+                let (_, (grand_label, grandchild)) = child.children.drain().next().unwrap();
+
+                let mut merged_label = label;
+                merged_label.extend(grand_label);
+
+                self.children.insert(first, (merged_label, grandchild));
+            }
+        }
+
+        removed
+    }
+
+    /// Returns the entries whose key starts with the given prefix.
+    /// Entries are returned in a tuple with their associated value.
+    pub fn starts_with(&self, prefix: impl IntoIterator<Item = K>) -> Vec<(Vec<K>, V)> {
+        let prefix: Vec<K> = prefix.into_iter().collect();
+        let mut node = self;
+        let mut consumed: Vec<K> = Vec::new();
+        let mut remaining = prefix.as_slice();
+
+        while !remaining.is_empty() {
+            let first = remaining[0].clone();
+
+            let (label, child) = match node.children.get(&first) {
+                Some(pair) => pair,
+                None => return Vec::new()
+            };
+
+            let common = common_prefix_len(label, remaining);
+
+            if common < label.len() && common < remaining.len() {
+                return Vec::new();
+            }
+
+            consumed.extend(label.iter().cloned());
+            node = child;
+            remaining = &remaining[common..];
+        }
+
+        node.collect_all(&consumed)
+    }
+
+    /// Walks the key sequence, returning the node it leads to, if any.
+    fn find_node(&self, key: &[K]) -> Option<&RadixTrie<K, V>> {
+        if key.is_empty() {
+            return Some(self);
+        }
+
+        let first = key[0].clone();
+        let (label, child) = self.children.get(&first)?;
+
+        if key.len() < label.len() || &key[..label.len()] != label.as_slice() {
+            return None;
+        }
+
+        child.find_node(&key[label.len()..])
+    }
+
+    /// Collects every value stored in this subtree, reconstructing each
+    /// full key as `base` plus the edge labels walked to reach it.
+    fn collect_all(&self, base: &[K]) -> Vec<(Vec<K>, V)> {
+        let mut words = Vec::new();
+
+        if let Some(value) = &self.value {
+            words.push((base.to_vec(), value.clone()));
+        }
+
+        for (label, child) in self.children.values() {
+            let mut next = base.to_vec();
+            next.extend(label.iter().cloned());
+
+            words.extend(child.collect_all(&next));
+        }
+
+        words
+    }
+}
+
+/// Thin `&str` convenience layer over `RadixTrie<char, V>`.
+impl<V: Clone> RadixTrie<char, V> {
+    /// Returns `true` if the given word exists.
+    pub fn contains_str(&self, word: &str) -> bool {
+        self.contains(word.chars())
+    }
+
+    /// Returns all the words from the Trie.
+    pub fn get_all_str(&self) -> Vec<(String, V)> {
+        self.starts_with_str("")
+    }
+
+    /// Creates the nodes that represent a new word.
+    pub fn insert_str(&mut self, word: &str, value: Option<V>) {
+        self.insert(word.chars(), value);
+    }
+
+    /// Removes a word, pruning dead edges and re-merging a node with
+    /// its sole remaining child as necessary.
+    pub fn remove_str(&mut self, word: &str) -> bool {
+        self.remove(word.chars())
+    }
+
+    /// Returns the words that start with the given prefix.
+    /// Words are returned in a tuple with their associated value.
+    pub fn starts_with_str(&self, prefix: &str) -> Vec<(String, V)> {
+        self.starts_with(prefix.chars())
+            .into_iter()
+            .map(|(chars, value)| (chars.into_iter().collect(), value))
+            .collect()
+    }
+}
+
+/// Stateful matcher that scans an incoming character stream and reports
+/// a hit as soon as the suffix of everything seen so far equals a word
+/// from the dictionary it was built with.
+pub struct StreamChecker<V> {
+    trie: Trie<char, V>,
+    max_len: usize,
+    buffer: VecDeque<char>
+}
+
+impl<V: Clone> StreamChecker<V> {
+    /// Builds a stream checker from a dictionary of words.
+    pub fn new(dict: &Trie<char, V>) -> Self {
+        // Match against the reversed words so `query` can walk forward
+        // over the buffer of recently seen characters (newest first).
+        let mut trie = Trie::new();
+        let mut max_len = 0;
+
+        for (word, value) in dict.get_all_str() {
+            max_len = max_len.max(word.chars().count());
+
+            let reversed: String = word.chars().rev().collect();
+            trie.insert_str(&reversed, Some(value));
+        }
+
+        Self { trie, max_len, buffer: VecDeque::new() }
+    }
+
+    /// Feeds one more character from the stream, returning `true` if the
+    /// suffix of the stream seen so far equals a stored word.
+    pub fn query(&mut self, c: char) -> bool {
+        self.buffer.push_front(c);
+
+        if self.buffer.len() > self.max_len {
+            self.buffer.pop_back();
+        }
+
+        let mut node = &self.trie;
+
+        for &c in self.buffer.iter() {
+            match node.children.get(&c) {
+                Some(next_node) => node = next_node,
+                None => return false
+            }
+
+            if node.value.is_some() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Clears the stream, forgetting everything seen so far.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+    }
 }
 
 #[cfg(test)]
@@ -170,11 +688,11 @@ mod tests {
         "blatte"
     ];
 
-    fn bootstrap() -> Trie<usize> {
+    fn bootstrap() -> Trie<char, usize> {
         let mut t = Trie::new();
 
         for (i, w) in DICT.iter().enumerate() {
-            t.insert(w, Some(i + 1));
+            t.insert_str(w, Some(i + 1));
         }
 
         t
@@ -184,24 +702,24 @@ mod tests {
     fn contains() {
         let t = bootstrap();
 
-        assert_eq!(t.contains("bol"), true);
-        assert_eq!(t.contains("b"), false);
-        assert_eq!(t.contains("figue"), false);
+        assert!(t.contains_str("bol"));
+        assert!(!t.contains_str("b"));
+        assert!(!t.contains_str("figue"));
     }
 
     #[test]
     fn remove() {
         let mut t = bootstrap();
 
-        t.remove("bol");
-        t.remove("blanche");
-        t.remove("blâme");
+        t.remove_str("bol");
+        t.remove_str("blanche");
+        t.remove_str("blâme");
 
-        assert_eq!(t.contains("blanc"), true);
+        assert!(t.contains_str("blanc"));
 
-        t.remove("blanc");
+        t.remove_str("blanc");
 
-        assert!(eq_unordered(&t.get_all(), &[
+        assert!(eq_unordered(&t.get_all_str(), &[
             ("bleu".to_string(), 1),
             ("blatte".to_string(), 6)
         ]));
@@ -211,7 +729,177 @@ mod tests {
     fn starts_with() {
         let t = bootstrap();
 
-        assert!(eq_unordered(&t.starts_with("b"), &[
+        assert!(eq_unordered(&t.starts_with_str("b"), &[
+            ("bleu".to_string(), 1),
+            ("blanc".to_string(), 2),
+            ("blanche".to_string(), 3),
+            ("bol".to_string(), 4),
+            ("blâme".to_string(), 5),
+            ("blatte".to_string(), 6)
+        ]));
+        assert!(eq_unordered(&t.starts_with_str("bla"), &[
+            ("blanc".to_string(), 2),
+            ("blanche".to_string(), 3),
+            ("blatte".to_string(), 6)
+        ]));
+        assert!(eq_unordered(&t.starts_with_str("bol"), &[("bol".to_string(), 4)]));
+        assert!(eq_unordered(&t.starts_with_str("z"), &[]));
+    }
+
+    #[test]
+    fn search_fuzzy() {
+        let t = bootstrap();
+
+        assert!(eq_unordered(&t.search_fuzzy_str("blanc", 0), &[
+            ("blanc".to_string(), 2)
+        ]));
+        assert!(eq_unordered(&t.search_fuzzy_str("blanc", 2), &[
+            ("blanc".to_string(), 2),
+            ("blanche".to_string(), 3)
+        ]));
+        assert!(eq_unordered(&t.search_fuzzy_str("zzzzz", 1), &[]));
+    }
+
+    #[test]
+    fn find_prefixes() {
+        let t = bootstrap();
+
+        assert_eq!(t.find_prefixes_str("blanches"), vec![
+            ("blanc".to_string(), &2),
+            ("blanche".to_string(), &3)
+        ]);
+        assert_eq!(t.find_longest_prefix_str("blanches"), Some(("blanche".to_string(), &3)));
+        assert_eq!(t.find_prefixes_str("xyz"), Vec::<(String, &usize)>::new());
+        assert_eq!(t.find_longest_prefix_str("xyz"), None);
+    }
+
+    #[test]
+    fn search_prefix_suffix() {
+        let mut t = Trie::with_reverse_index();
+
+        for (i, w) in DICT.iter().enumerate() {
+            t.insert_str(w, Some(i + 1));
+        }
+
+        assert!(eq_unordered(&t.search_prefix_suffix_str("bl", "e"), &[
+            ("blanche".to_string(), 3),
+            ("blâme".to_string(), 5),
+            ("blatte".to_string(), 6)
+        ]));
+        assert!(eq_unordered(&t.search_prefix_suffix_str("bl", "z"), &[]));
+
+        t.remove_str("blanche");
+        assert!(eq_unordered(&t.search_prefix_suffix_str("bl", "e"), &[
+            ("blâme".to_string(), 5),
+            ("blatte".to_string(), 6)
+        ]));
+
+        // Without a reverse index, the query is a no-op.
+        assert_eq!(bootstrap().search_prefix_suffix_str("bl", "e"), Vec::<(String, usize)>::new());
+    }
+
+    #[test]
+    fn stream_checker() {
+        let t = bootstrap();
+        let mut checker = StreamChecker::new(&t);
+        let mut hits = Vec::new();
+
+        for c in "xxbleuxxbolxx".chars() {
+            hits.push(checker.query(c));
+        }
+
+        assert_eq!(hits, vec![
+            false, false, false, false, false, true, false, false, false, false, true, false, false
+        ]);
+
+        checker.reset();
+        assert!(!checker.query('x'));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let t = bootstrap();
+
+        let json = serde_json::to_string(&t).unwrap();
+        let back: Trie<char, usize> = serde_json::from_str(&json).unwrap();
+
+        assert!(eq_unordered(&back.get_all_str(), &t.get_all_str()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_reverse_index() {
+        let mut t = Trie::with_reverse_index();
+
+        for (i, w) in DICT.iter().enumerate() {
+            t.insert_str(w, Some(i + 1));
+        }
+
+        let json = serde_json::to_string(&t).unwrap();
+        let back: Trie<char, usize> = serde_json::from_str(&json).unwrap();
+
+        assert!(eq_unordered(&back.search_prefix_suffix_str("bl", "e"), &[
+            ("blanche".to_string(), 3),
+            ("blâme".to_string(), 5),
+            ("blatte".to_string(), 6)
+        ]));
+    }
+
+    #[test]
+    fn generic_key_bytes() {
+        let mut t: Trie<u8, &str> = Trie::new();
+
+        t.insert("on".bytes(), Some("fr"));
+        t.insert("ok".bytes(), Some("en"));
+
+        assert!(t.contains("on".bytes()));
+        assert!(!t.contains("oz".bytes()));
+        assert_eq!(t.starts_with("o".bytes()).len(), 2);
+    }
+
+    fn bootstrap_radix() -> RadixTrie<char, usize> {
+        let mut t = RadixTrie::new();
+
+        for (i, w) in DICT.iter().enumerate() {
+            t.insert_str(w, Some(i + 1));
+        }
+
+        t
+    }
+
+    #[test]
+    fn radix_contains() {
+        let t = bootstrap_radix();
+
+        assert!(t.contains_str("bol"));
+        assert!(!t.contains_str("b"));
+        assert!(!t.contains_str("figue"));
+    }
+
+    #[test]
+    fn radix_remove() {
+        let mut t = bootstrap_radix();
+
+        t.remove_str("bol");
+        t.remove_str("blanche");
+        t.remove_str("blâme");
+
+        assert!(t.contains_str("blanc"));
+
+        t.remove_str("blanc");
+
+        assert!(eq_unordered(&t.get_all_str(), &[
+            ("bleu".to_string(), 1),
+            ("blatte".to_string(), 6)
+        ]));
+    }
+
+    #[test]
+    fn radix_starts_with() {
+        let t = bootstrap_radix();
+
+        assert!(eq_unordered(&t.starts_with_str("b"), &[
             ("bleu".to_string(), 1),
             ("blanc".to_string(), 2),
             ("blanche".to_string(), 3),
@@ -219,12 +907,12 @@ mod tests {
             ("blâme".to_string(), 5),
             ("blatte".to_string(), 6)
         ]));
-        assert!(eq_unordered(&t.starts_with("bla"), &[
+        assert!(eq_unordered(&t.starts_with_str("bla"), &[
             ("blanc".to_string(), 2),
             ("blanche".to_string(), 3),
             ("blatte".to_string(), 6)
         ]));
-        assert!(eq_unordered(&t.starts_with("bol"), &[("bol".to_string(), 4)]));
-        assert!(eq_unordered(&t.starts_with("z"), &[]));
+        assert!(eq_unordered(&t.starts_with_str("bol"), &[("bol".to_string(), 4)]));
+        assert!(eq_unordered(&t.starts_with_str("z"), &[]));
     }
 }